@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate newstr;
+
+use std::str::FromStr;
+
+constrained_newstring!(
+    Identifier,
+    IdentifierError,
+    not_empty,
+    max_len = 20,
+    min_len = 3,
+    alphanumeric
+);
+
+constrained_newstring!(Greeting, GreetingError, not_empty, matches = r"^[Hh]ello");
+
+#[test]
+fn check_builtin_constraints() {
+    assert!(!Identifier::is_valid(""));
+    assert!(!Identifier::is_valid("hi"));
+    assert!(!Identifier::is_valid("hello world"));
+    assert!(!Identifier::is_valid("this_identifier_is_too_long"));
+
+    assert!(Identifier::is_valid("hello"));
+    assert!(Identifier::from_str("hello").is_ok());
+}
+
+#[test]
+fn check_matches_constraint() {
+    assert!(Greeting::is_valid("hello there"));
+    assert!(!Greeting::is_valid("goodbye"));
+}
+
+#[test]
+fn check_error_variants() {
+    assert_eq!(Identifier::from_str("").unwrap_err(), IdentifierError::Empty);
+    assert_eq!(
+        Identifier::from_str("hi").unwrap_err(),
+        IdentifierError::TooShort { min: 3, actual: 2 }
+    );
+    assert_eq!(
+        Identifier::from_str("this_identifier_is_too_long").unwrap_err(),
+        IdentifierError::TooLong { max: 20, actual: 27 }
+    );
+    assert_eq!(
+        Identifier::from_str("hello world").unwrap_err(),
+        IdentifierError::NotAlphanumeric
+    );
+    assert_eq!(
+        Greeting::from_str("goodbye").unwrap_err(),
+        GreetingError::PatternMismatch
+    );
+}
@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate newstr;
+
+use std::str::FromStr;
+
+parse_error_newstring!(IdentifierParseError, Identifier);
+
+is_valid_newstring!(
+    Identifier,
+    |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+    IdentifierParseError
+);
+
+#[test]
+fn check_valid_value() {
+    assert!(Identifier::from_str("hello_world").is_ok());
+}
+
+#[test]
+fn check_error_message() {
+    let error = Identifier::from_str("hello world").unwrap_err();
+    assert_eq!(error.to_string(), "invalid Identifier: \"hello world\"");
+}
+
+#[test]
+fn check_error_input() {
+    let error = Identifier::from_str("hello world").unwrap_err();
+    assert_eq!(error.input(), "hello world");
+}
@@ -0,0 +1,32 @@
+#[macro_use]
+extern crate newstr;
+
+use std::str::FromStr;
+
+sanitized_newstring!(Name, |s: &str| !s.is_empty(), trim, lowercase);
+
+sanitized_newstring!(
+    Shout,
+    |s: &str| !s.is_empty(),
+    trim,
+    (|s: &str| -> String { s.chars().filter(|c| !c.is_whitespace()).collect() }),
+    uppercase
+);
+
+#[test]
+fn check_trim_and_lowercase() {
+    let name = Name::from_str("  Foo ").unwrap();
+    assert_eq!(name.to_string(), String::from("foo"));
+}
+
+#[test]
+fn check_is_valid_reports_on_sanitized_form() {
+    assert!(Name::is_valid("  Foo "));
+    assert!(!Name::is_valid("   "));
+}
+
+#[test]
+fn check_custom_closure_step() {
+    let shout = Shout::from_str(" hello world ").unwrap();
+    assert_eq!(shout.to_string(), String::from("HELLOWORLD"));
+}
@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate newstr;
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_uppercase_only(s: &str) -> Result<String, ParseError> {
+    if s.chars().all(|c| c.is_uppercase()) {
+        Ok(s.to_string())
+    } else {
+        Err(ParseError)
+    }
+}
+
+from_str_newstring!(OnlyUpperCase, parse_uppercase_only, ParseError);
+serde_newstring!(OnlyUpperCase);
+
+#[test]
+fn check_round_trip() {
+    let value = OnlyUpperCase::from_str("HELLO").unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "\"HELLO\"");
+
+    let parsed: OnlyUpperCase = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, value);
+}
+
+#[test]
+fn check_rejects_invalid_value() {
+    let result: Result<OnlyUpperCase, _> = serde_json::from_str("\"hello\"");
+    assert!(result.is_err());
+}
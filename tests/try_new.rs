@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate newstr;
+
+use std::convert::TryFrom;
+
+is_valid_newstring!(AsciiStr, str::is_ascii);
+try_new!(pub AsciiStr);
+
+#[test]
+fn check_try_new() {
+    assert!(AsciiStr::try_new("hello").is_ok());
+    assert!(AsciiStr::try_new("héllo").is_err());
+}
+
+#[test]
+fn check_try_from_string() {
+    let result = AsciiStr::try_from(String::from("hello"));
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().to_string(), String::from("hello"));
+}
+
+#[test]
+fn check_try_from_str() {
+    assert!(AsciiStr::try_from("hello").is_ok());
+    assert!(AsciiStr::try_from("héllo").is_err());
+}